@@ -1,13 +1,14 @@
+use std::borrow::Cow;
 use std::fmt::{Display, Error};
 
 #[derive(Default, Debug, Copy, Clone, PartialEq, Eq)]
-struct Location {
+pub(crate) struct Location {
     pub line: u32,
     pub column: u32,
 }
 
 #[derive(Debug, Copy, Clone, PartialEq, Eq)]
-enum Keyword {
+pub(crate) enum Keyword {
     Select,
     From,
     As,
@@ -21,7 +22,7 @@ enum Keyword {
 }
 
 #[derive(Debug, Copy, Clone, PartialEq, Eq)]
-enum Symbol {
+pub(crate) enum Symbol {
     Semicolon,
     Asterisk,
     Comma,
@@ -30,32 +31,55 @@ enum Symbol {
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
-enum TokenValue<'value> {
+pub(crate) enum TokenValue<'value> {
     Keyword(Keyword),
     Symbol(Symbol),
     Identifier(&'value str),
-    String(&'value str),
+    String(Cow<'value, str>),
     Number(i64),
+    Comment(&'value str),
+}
+
+/// A byte-offset range into the source, independent of the human-readable
+/// [`Location`]. Lets tooling recover the exact slice that produced a token
+/// without re-lexing.
+#[derive(Default, Debug, Copy, Clone, PartialEq, Eq)]
+pub struct Span {
+    pub start: u32,
+    pub end: u32,
+}
+
+impl Span {
+    pub fn text<'source>(&self, source: &'source str) -> &'source str {
+        &source[self.start as usize..self.end as usize]
+    }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct Token<'token_value> {
-    value: TokenValue<'token_value>,
-    location: Location,
+    pub(crate) value: TokenValue<'token_value>,
+    pub(crate) location: Location,
+    span: Span,
+}
+
+impl Token<'_> {
+    pub fn span(&self) -> Span {
+        self.span
+    }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 struct LexerResult<'token_value> {
     token_value: TokenValue<'token_value>,
-    chars: usize,
+    bytes: usize,
     lines: u32,
     columns: u32,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Eq)]
 pub struct ParseError<'token_value> {
-    location: Location,
-    last: Option<Token<'token_value>>,
+    pub(crate) location: Location,
+    pub(crate) last: Option<Token<'token_value>>,
 }
 
 impl Display for ParseError<'_> {
@@ -73,50 +97,404 @@ impl Display for ParseError<'_> {
 
 impl std::error::Error for ParseError<'_> {}
 
-type Lexer = fn(&str) -> Option<LexerResult>;
+type Lexer = fn(&str) -> Option<LexerResult<'_>>;
+
+fn is_identifier_start(c: char) -> bool {
+    c.is_ascii_alphabetic() || c == '_'
+}
+
+fn is_identifier_continue(c: char) -> bool {
+    c.is_ascii_alphanumeric() || c == '_'
+}
+
+fn skip_whitespace(source: &str) -> &str {
+    source.trim_start_matches(char::is_whitespace)
+}
+
+/// Measures a matched slice in the shape `LexerResult` expects: `bytes` is
+/// the slice's length for advancing the byte cursor, `lines`/`columns` are
+/// char-counted for human-readable `Location` math, where `columns` is an
+/// absolute column when the match spans a newline, or a delta to add to the
+/// current column otherwise.
+fn measure(matched: &str) -> (usize, u32, u32) {
+    let bytes = matched.len();
+    let lines = matched.matches('\n').count() as u32;
+    let columns = matched.rsplit('\n').next().unwrap().chars().count() as u32;
+    (bytes, lines, columns)
+}
+
+fn lex_keyword<'source>(
+    source: &'source str,
+    text: &str,
+    keyword: Keyword,
+) -> Option<LexerResult<'source>> {
+    let trimmed = skip_whitespace(source);
+    let mut source_chars = trimmed.char_indices();
+    for expected in text.chars() {
+        let (_, actual) = source_chars.next()?;
+        if !actual.eq_ignore_ascii_case(&expected) {
+            return None;
+        }
+    }
+    let after = source_chars.as_str();
+    if after.chars().next().is_some_and(is_identifier_continue) {
+        return None;
+    }
+    let matched = &source[..source.len() - after.len()];
+    let (bytes, lines, columns) = measure(matched);
+    Some(LexerResult {
+        token_value: TokenValue::Keyword(keyword),
+        bytes,
+        lines,
+        columns,
+    })
+}
+
+fn lex_symbol(source: &str, symbol_char: char, symbol: Symbol) -> Option<LexerResult<'_>> {
+    let trimmed = skip_whitespace(source);
+    let mut chars = trimmed.chars();
+    if chars.next()? != symbol_char {
+        return None;
+    }
+    let after = chars.as_str();
+    let matched = &source[..source.len() - after.len()];
+    let (bytes, lines, columns) = measure(matched);
+    Some(LexerResult {
+        token_value: TokenValue::Symbol(symbol),
+        bytes,
+        lines,
+        columns,
+    })
+}
+
+macro_rules! keyword_lexer {
+    ($name:ident, $keyword:expr, $text:literal) => {
+        fn $name(source: &str) -> Option<LexerResult<'_>> {
+            lex_keyword(source, $text, $keyword)
+        }
+    };
+}
+
+keyword_lexer!(lex_select, Keyword::Select, "select");
+keyword_lexer!(lex_from, Keyword::From, "from");
+keyword_lexer!(lex_as, Keyword::As, "as");
+keyword_lexer!(lex_table, Keyword::Table, "table");
+keyword_lexer!(lex_create, Keyword::Create, "create");
+keyword_lexer!(lex_insert, Keyword::Insert, "insert");
+keyword_lexer!(lex_into, Keyword::Into, "into");
+keyword_lexer!(lex_values, Keyword::Values, "values");
+keyword_lexer!(lex_int, Keyword::Int, "int");
+keyword_lexer!(lex_text, Keyword::Text, "text");
+
+macro_rules! symbol_lexer {
+    ($name:ident, $symbol:expr, $char:literal) => {
+        fn $name(source: &str) -> Option<LexerResult<'_>> {
+            lex_symbol(source, $char, $symbol)
+        }
+    };
+}
+
+symbol_lexer!(lex_semicolon, Symbol::Semicolon, ';');
+symbol_lexer!(lex_asterisk, Symbol::Asterisk, '*');
+symbol_lexer!(lex_comma, Symbol::Comma, ',');
+symbol_lexer!(lex_left_paren, Symbol::LeftParen, '(');
+symbol_lexer!(lex_right_paren, Symbol::RightParen, ')');
+
+fn lex_identifier(source: &str) -> Option<LexerResult<'_>> {
+    let trimmed = skip_whitespace(source);
+    let mut chars = trimmed.char_indices();
+    let (_, first) = chars.next()?;
+    if !is_identifier_start(first) {
+        return None;
+    }
+    let end = chars
+        .find(|(_, c)| !is_identifier_continue(*c))
+        .map_or(trimmed.len(), |(index, _)| index);
+    let identifier = &trimmed[..end];
+    let after = &trimmed[end..];
+    let matched = &source[..source.len() - after.len()];
+    let (bytes, lines, columns) = measure(matched);
+    Some(LexerResult {
+        token_value: TokenValue::Identifier(identifier),
+        bytes,
+        lines,
+        columns,
+    })
+}
+
+/// Scans a single-quoted string literal, decoding `\n`/`\t`/`\r`/`\\` escapes
+/// and doubled `''` quotes into their real characters. Returns the byte
+/// offset of the closing quote and, if any escape was decoded, the owned
+/// replacement content (so a plain literal can stay a zero-copy borrow).
+fn scan_string_content(trimmed: &str) -> Option<(usize, Option<String>)> {
+    let mut chars = trimmed.char_indices();
+    let (_, quote) = chars.next()?;
+    if quote != '\'' {
+        return None;
+    }
+
+    let mut decoded = String::new();
+    let mut has_escapes = false;
+    let mut escaped = false;
+    while let Some((index, c)) = chars.next() {
+        if escaped {
+            decoded.push(match c {
+                'n' => '\n',
+                't' => '\t',
+                'r' => '\r',
+                other => other,
+            });
+            escaped = false;
+        } else if c == '\\' {
+            has_escapes = true;
+            escaped = true;
+        } else if c == '\'' {
+            if trimmed[index + 1..].starts_with('\'') {
+                has_escapes = true;
+                decoded.push('\'');
+                chars.next();
+            } else {
+                return Some((index, has_escapes.then_some(decoded)));
+            }
+        } else {
+            decoded.push(c);
+        }
+    }
+    None
+}
+
+fn lex_string(source: &str) -> Option<LexerResult<'_>> {
+    let trimmed = skip_whitespace(source);
+    let (content_end, decoded) = scan_string_content(trimmed)?;
+    let content = decoded.map_or_else(|| Cow::Borrowed(&trimmed[1..content_end]), Cow::Owned);
+    let after = &trimmed[content_end + 1..];
+    let matched = &source[..source.len() - after.len()];
+    let (bytes, lines, columns) = measure(matched);
+    Some(LexerResult {
+        token_value: TokenValue::String(content),
+        bytes,
+        lines,
+        columns,
+    })
+}
+
+fn lex_number(source: &str) -> Option<LexerResult<'_>> {
+    let trimmed = skip_whitespace(source);
+    let mut chars = trimmed.char_indices();
+    let (_, first) = chars.next()?;
+    if !first.is_ascii_digit() {
+        return None;
+    }
+    let end = chars
+        .find(|(_, c)| !c.is_ascii_digit())
+        .map_or(trimmed.len(), |(index, _)| index);
+    let digits = &trimmed[..end];
+    let after = &trimmed[end..];
+    let matched = &source[..source.len() - after.len()];
+    let (bytes, lines, columns) = measure(matched);
+    let value = digits.parse().ok()?;
+    Some(LexerResult {
+        token_value: TokenValue::Number(value),
+        bytes,
+        lines,
+        columns,
+    })
+}
+
+fn lex_line_comment(source: &str) -> Option<LexerResult<'_>> {
+    let trimmed = skip_whitespace(source);
+    let rest = trimmed.strip_prefix("--")?;
+    let end = rest.find('\n').unwrap_or(rest.len());
+    let content = &rest[..end];
+    let after = &rest[end..];
+    let matched = &source[..source.len() - after.len()];
+    let (bytes, lines, columns) = measure(matched);
+    Some(LexerResult {
+        token_value: TokenValue::Comment(content),
+        bytes,
+        lines,
+        columns,
+    })
+}
+
+fn lex_block_comment(source: &str) -> Option<LexerResult<'_>> {
+    let trimmed = skip_whitespace(source);
+    let rest = trimmed.strip_prefix("/*")?;
+    let end = rest.find("*/")?;
+    let content = &rest[..end];
+    let after = &rest[end + 2..];
+    let matched = &source[..source.len() - after.len()];
+    let (bytes, lines, columns) = measure(matched);
+    Some(LexerResult {
+        token_value: TokenValue::Comment(content),
+        bytes,
+        lines,
+        columns,
+    })
+}
+
+const LEXERS: [Lexer; 20] = [
+    lex_line_comment,
+    lex_block_comment,
+    lex_select,
+    lex_from,
+    lex_as,
+    lex_table,
+    lex_create,
+    lex_insert,
+    lex_into,
+    lex_values,
+    lex_int,
+    lex_text,
+    lex_semicolon,
+    lex_asterisk,
+    lex_comma,
+    lex_left_paren,
+    lex_right_paren,
+    lex_identifier,
+    lex_string,
+    lex_number,
+];
+
+const BOUNDARY_SYMBOLS: [char; 5] = [';', '*', ',', '(', ')'];
 
-pub fn lex(source: &str) -> Result<Vec<Token>, ParseError> {
-    const LEXERS: [Lexer; 0] = [];
+fn is_boundary(c: char) -> bool {
+    c.is_whitespace() || BOUNDARY_SYMBOLS.contains(&c)
+}
+
+/// Finds where to resume lexing after an unrecognized slice: always skips
+/// past the offending char (to guarantee progress), then past whatever
+/// follows it up to the next whitespace or symbol boundary.
+fn skip_past_unrecognized(slice: &str) -> &str {
+    let mut chars = slice.char_indices();
+    chars.next();
+    let end = chars
+        .find(|(_, c)| is_boundary(*c))
+        .map_or(slice.len(), |(index, _)| index);
+    &slice[end..]
+}
 
+fn advance(
+    location: &mut Location,
+    byte_index: &mut usize,
+    bytes: usize,
+    lines: u32,
+    columns: u32,
+) {
+    *byte_index += bytes;
+    location.line += lines;
+    if lines > 0 {
+        location.column = columns;
+    } else {
+        location.column += columns;
+    }
+}
+
+/// Runs the lexer over the whole source, either stopping at the first
+/// unrecognized slice (`recover = false`) or skipping past it and continuing
+/// to accumulate every error (`recover = true`), keeping `Comment` tokens in
+/// the output only if `keep_comments` is set.
+fn lex_with(
+    source: &str,
+    recover: bool,
+    keep_comments: bool,
+) -> (Vec<Token<'_>>, Vec<ParseError<'_>>) {
     let mut tokens = Vec::<Token>::new();
+    let mut errors = Vec::<ParseError>::new();
     let mut location = Location::default();
 
-    let char_indices = source
-        .char_indices()
-        .map(|(index, _)| index)
-        .collect::<Vec<_>>();
-    let num_chars = char_indices.len();
-    let mut char_index = 0;
-    while char_index < num_chars {
-        let slice = &source[char_index..];
-        let Some(result) = LEXERS
-            .iter()
-            .find_map(|lexer| lexer(slice)) else {
-        return Err(ParseError {
-            location,
-            last: tokens.pop(),
-        })};
-
-        let (token_value, chars, lines, columns) = (
-            result.token_value,
-            result.chars,
-            result.lines,
-            result.columns,
-        );
-        tokens.push(Token {
-            value: token_value,
-            location,
-        });
-        char_index += chars;
-        location.line += lines;
-        if lines > 0 {
-            location.column = columns;
-        } else {
-            location.column += columns;
+    let len = source.len();
+    let mut byte_index = 0;
+    while byte_index < len {
+        let slice = &source[byte_index..];
+        let leading_whitespace = slice.len() - skip_whitespace(slice).len();
+        if leading_whitespace > 0 {
+            let (bytes, lines, columns) = measure(&slice[..leading_whitespace]);
+            advance(&mut location, &mut byte_index, bytes, lines, columns);
+            if byte_index >= len {
+                break;
+            }
+        }
+
+        let slice = &source[byte_index..];
+        match LEXERS.iter().find_map(|lexer| lexer(slice)) {
+            Some(result) => {
+                let start_location = location;
+                let start = byte_index;
+                advance(
+                    &mut location,
+                    &mut byte_index,
+                    result.bytes,
+                    result.lines,
+                    result.columns,
+                );
+                if keep_comments || !matches!(result.token_value, TokenValue::Comment(_)) {
+                    tokens.push(Token {
+                        value: result.token_value,
+                        location: start_location,
+                        span: Span {
+                            start: start as u32,
+                            end: byte_index as u32,
+                        },
+                    });
+                }
+            }
+            None => {
+                errors.push(ParseError {
+                    location,
+                    last: tokens.last().cloned(),
+                });
+                if !recover {
+                    break;
+                }
+                let rest = skip_past_unrecognized(slice);
+                let matched = &slice[..slice.len() - rest.len()];
+                let (bytes, lines, columns) = measure(matched);
+                advance(&mut location, &mut byte_index, bytes, lines, columns);
+            }
         }
     }
 
-    Ok(tokens)
+    (tokens, errors)
+}
+
+fn finish<'a>(
+    tokens: Vec<Token<'a>>,
+    mut errors: Vec<ParseError<'a>>,
+) -> Result<Vec<Token<'a>>, ParseError<'a>> {
+    match errors.pop() {
+        Some(error) => Err(error),
+        None => Ok(tokens),
+    }
+}
+
+pub fn lex(source: &str) -> Result<Vec<Token<'_>>, ParseError<'_>> {
+    let (tokens, errors) = lex_with(source, false, false);
+    finish(tokens, errors)
+}
+
+/// Options for [`lex_with_options`].
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq)]
+pub struct LexOptions {
+    /// Instead of stopping at the first unrecognized slice, skip past it and
+    /// keep going, accumulating every error hit alongside every token
+    /// recognized. Useful for editor/diagnostic tooling that wants to report
+    /// all malformed tokens in one pass.
+    pub recover: bool,
+    /// Keep `--` and `/* */` comments in the token stream instead of
+    /// stripping them, for tools (formatters, linters) that need to
+    /// faithfully round-trip the source.
+    pub keep_comments: bool,
+}
+
+/// Like [`lex`], but configurable via [`LexOptions`] and returning the tokens
+/// and errors as a pair instead of a `Result`. Without `recover`, lexing
+/// still stops at the first unrecognized slice, leaving at most one error.
+pub fn lex_with_options(
+    source: &str,
+    options: LexOptions,
+) -> (Vec<Token<'_>>, Vec<ParseError<'_>>) {
+    lex_with(source, options.recover, options.keep_comments)
 }
 
 #[cfg(test)]
@@ -131,7 +509,213 @@ mod test {
 
     #[test]
     fn nonsense() {
-        let tokens = lex("deadbeef");
+        let tokens = lex("@@@");
         assert!(tokens.is_err());
     }
+
+    #[test]
+    fn select_statement() {
+        let tokens = lex("SELECT * FROM t;").unwrap();
+        assert_eq!(
+            tokens.iter().map(|token| &token.value).collect::<Vec<_>>(),
+            vec![
+                &TokenValue::Keyword(Keyword::Select),
+                &TokenValue::Symbol(Symbol::Asterisk),
+                &TokenValue::Keyword(Keyword::From),
+                &TokenValue::Identifier("t"),
+                &TokenValue::Symbol(Symbol::Semicolon),
+            ]
+        );
+    }
+
+    #[test]
+    fn non_ascii_strings_dont_desync_the_cursor() {
+        let tokens = lex("'hé' x;").unwrap();
+        assert_eq!(
+            tokens.iter().map(|token| &token.value).collect::<Vec<_>>(),
+            vec![
+                &TokenValue::String(Cow::Borrowed("hé")),
+                &TokenValue::Identifier("x"),
+                &TokenValue::Symbol(Symbol::Semicolon),
+            ]
+        );
+    }
+
+    #[test]
+    fn multibyte_content_does_not_panic() {
+        let tokens = lex("'日本語' x;").unwrap();
+        assert_eq!(
+            tokens.iter().map(|token| &token.value).collect::<Vec<_>>(),
+            vec![
+                &TokenValue::String(Cow::Borrowed("日本語")),
+                &TokenValue::Identifier("x"),
+                &TokenValue::Symbol(Symbol::Semicolon),
+            ]
+        );
+    }
+
+    #[test]
+    fn keyword_prefix_does_not_split_identifier() {
+        let tokens = lex("selection").unwrap();
+        assert_eq!(tokens.len(), 1);
+        assert_eq!(tokens[0].value, TokenValue::Identifier("selection"));
+    }
+
+    #[test]
+    fn plain_string_borrows_source() {
+        let tokens = lex("'hi'").unwrap();
+        assert_eq!(tokens[0].value, TokenValue::String(Cow::Borrowed("hi")));
+        assert!(matches!(
+            &tokens[0].value,
+            TokenValue::String(Cow::Borrowed(_))
+        ));
+    }
+
+    #[test]
+    fn string_escapes_are_decoded() {
+        let tokens = lex(r"'it''s\n\t\\'").unwrap();
+        assert_eq!(
+            tokens[0].value,
+            TokenValue::String(Cow::Owned("it's\n\t\\".to_string()))
+        );
+    }
+
+    #[test]
+    fn unterminated_string_is_an_error() {
+        assert!(lex("'oops").is_err());
+    }
+
+    #[test]
+    fn dangling_backslash_is_an_error() {
+        assert!(lex(r"'oops\").is_err());
+    }
+
+    #[test]
+    fn lex_recover_collects_every_error() {
+        let (tokens, errors) = lex_with_options(
+            "@@@ SELECT @@@ FROM t;",
+            LexOptions {
+                recover: true,
+                ..Default::default()
+            },
+        );
+        assert_eq!(errors.len(), 2);
+        assert_eq!(
+            tokens.iter().map(|token| &token.value).collect::<Vec<_>>(),
+            vec![
+                &TokenValue::Keyword(Keyword::Select),
+                &TokenValue::Keyword(Keyword::From),
+                &TokenValue::Identifier("t"),
+                &TokenValue::Symbol(Symbol::Semicolon),
+            ]
+        );
+    }
+
+    #[test]
+    fn lex_recover_matches_lex_on_clean_input() {
+        let (tokens, errors) = lex_with_options(
+            "SELECT * FROM t;",
+            LexOptions {
+                recover: true,
+                ..Default::default()
+            },
+        );
+        assert!(errors.is_empty());
+        assert_eq!(tokens, lex("SELECT * FROM t;").unwrap());
+    }
+
+    #[test]
+    fn span_recovers_source_text() {
+        let source = "SELECT * FROM t;";
+        let tokens = lex(source).unwrap();
+        let texts = tokens
+            .iter()
+            .map(|token| token.span().text(source))
+            .collect::<Vec<_>>();
+        assert_eq!(texts, vec!["SELECT", "*", "FROM", "t", ";"]);
+    }
+
+    #[test]
+    fn span_is_a_byte_offset_with_non_ascii_source() {
+        let source = "-- héllo\nSELECT;";
+        let (tokens, errors) = lex_with_options(
+            source,
+            LexOptions {
+                keep_comments: true,
+                ..Default::default()
+            },
+        );
+        assert!(errors.is_empty());
+        let texts = tokens
+            .iter()
+            .map(|token| token.span().text(source))
+            .collect::<Vec<_>>();
+        assert_eq!(texts, vec!["-- héllo", "SELECT", ";"]);
+    }
+
+    #[test]
+    fn line_comments_are_stripped_by_default() {
+        let tokens = lex("SELECT -- comment\n*;").unwrap();
+        assert_eq!(
+            tokens.iter().map(|token| &token.value).collect::<Vec<_>>(),
+            vec![
+                &TokenValue::Keyword(Keyword::Select),
+                &TokenValue::Symbol(Symbol::Asterisk),
+                &TokenValue::Symbol(Symbol::Semicolon),
+            ]
+        );
+    }
+
+    #[test]
+    fn block_comments_are_stripped_by_default() {
+        let tokens = lex("SELECT /* skip\nme */ *;").unwrap();
+        assert_eq!(
+            tokens.iter().map(|token| &token.value).collect::<Vec<_>>(),
+            vec![
+                &TokenValue::Keyword(Keyword::Select),
+                &TokenValue::Symbol(Symbol::Asterisk),
+                &TokenValue::Symbol(Symbol::Semicolon),
+            ]
+        );
+    }
+
+    #[test]
+    fn keep_comments_preserves_comment_tokens() {
+        let (tokens, errors) = lex_with_options(
+            "SELECT -- hi\n*;",
+            LexOptions {
+                keep_comments: true,
+                ..Default::default()
+            },
+        );
+        assert!(errors.is_empty());
+        assert_eq!(
+            tokens.iter().map(|token| &token.value).collect::<Vec<_>>(),
+            vec![
+                &TokenValue::Keyword(Keyword::Select),
+                &TokenValue::Comment(" hi"),
+                &TokenValue::Symbol(Symbol::Asterisk),
+                &TokenValue::Symbol(Symbol::Semicolon),
+            ]
+        );
+    }
+
+    #[test]
+    fn unterminated_block_comment_is_an_error() {
+        assert!(lex("SELECT /* oops").is_err());
+    }
+
+    #[test]
+    fn comments_advance_line_and_column_across_newlines() {
+        let (tokens, errors) = lex_with_options(
+            "/* a\nb */ t;",
+            LexOptions {
+                keep_comments: true,
+                ..Default::default()
+            },
+        );
+        assert!(errors.is_empty());
+        assert_eq!(tokens[0].location, Location { line: 0, column: 0 });
+        assert_eq!(tokens[1].location, Location { line: 1, column: 5 });
+    }
 }
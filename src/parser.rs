@@ -0,0 +1,389 @@
+use std::iter::Peekable;
+use std::vec::IntoIter;
+
+use crate::lexer::{Keyword, Location, ParseError, Symbol, Token, TokenValue};
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Expr {
+    Identifier(String),
+    Number(i64),
+    String(String),
+    Star,
+    Tuple(Vec<Expr>),
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum ColumnType {
+    Int,
+    Text,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ColumnDef {
+    pub name: String,
+    pub column_type: ColumnType,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Statement {
+    Select {
+        columns: Vec<Expr>,
+        table: String,
+    },
+    CreateTable {
+        name: String,
+        columns: Vec<ColumnDef>,
+    },
+    Insert {
+        table: String,
+        values: Vec<Expr>,
+    },
+}
+
+/// Binding power for Pratt (precedence-climbing) expression parsing. Higher
+/// binds tighter.
+type BindingPower = u8;
+
+const MIN_BINDING_POWER: BindingPower = 0;
+
+type PrefixParselet = for<'token> fn(&mut Cursor<'token>, Token<'token>) -> ExprResult<'token>;
+type InfixParselet = for<'token> fn(&mut Cursor<'token>, Expr, BindingPower) -> ExprResult<'token>;
+
+type ExprResult<'token> = Result<Expr, ParseError<'token>>;
+
+/// A cursor over the token stream, tracking the last consumed token so a
+/// failed production can point at it the same way [`crate::lexer::lex`]
+/// points at the last successfully lexed token.
+struct Cursor<'token> {
+    tokens: Peekable<IntoIter<Token<'token>>>,
+    last: Option<Token<'token>>,
+}
+
+impl<'token> Cursor<'token> {
+    fn new(tokens: Vec<Token<'token>>) -> Self {
+        Cursor {
+            tokens: tokens.into_iter().peekable(),
+            last: None,
+        }
+    }
+
+    fn peek(&mut self) -> Option<&Token<'token>> {
+        self.tokens.peek()
+    }
+
+    fn next(&mut self) -> Option<Token<'token>> {
+        let token = self.tokens.next();
+        if let Some(token) = &token {
+            self.last = Some(token.clone());
+        }
+        token
+    }
+
+    /// A `ParseError` pointing at wherever parsing stalled: the end of the
+    /// token stream, reusing the last consumed token for context.
+    fn error(&self) -> ParseError<'token> {
+        ParseError {
+            location: self
+                .last
+                .as_ref()
+                .map_or_else(Location::default, |token| token.location),
+            last: self.last.clone(),
+        }
+    }
+
+    /// A `ParseError` pointing at a specific offending token.
+    fn error_at(&self, token: &Token<'token>) -> ParseError<'token> {
+        ParseError {
+            location: token.location,
+            last: self.last.clone(),
+        }
+    }
+
+    fn expect_keyword(&mut self, keyword: Keyword) -> Result<(), ParseError<'token>> {
+        match self.next() {
+            Some(token) if token.value == TokenValue::Keyword(keyword) => Ok(()),
+            Some(token) => Err(self.error_at(&token)),
+            None => Err(self.error()),
+        }
+    }
+
+    fn expect_symbol(&mut self, symbol: Symbol) -> Result<(), ParseError<'token>> {
+        match self.next() {
+            Some(token) if token.value == TokenValue::Symbol(symbol) => Ok(()),
+            Some(token) => Err(self.error_at(&token)),
+            None => Err(self.error()),
+        }
+    }
+
+    fn eat_symbol(&mut self, symbol: Symbol) -> bool {
+        match self.peek() {
+            Some(token) if token.value == TokenValue::Symbol(symbol) => {
+                self.next();
+                true
+            }
+            _ => false,
+        }
+    }
+
+    fn expect_identifier(&mut self) -> Result<String, ParseError<'token>> {
+        match self.next() {
+            Some(token) => match &token.value {
+                TokenValue::Identifier(name) => Ok((*name).to_string()),
+                _ => Err(self.error_at(&token)),
+            },
+            None => Err(self.error()),
+        }
+    }
+}
+
+/// Looks up the prefix parselet for a token kind, the first half of the
+/// token-kind-to-parse-function map that drives expression parsing. Returns
+/// `None` for a token kind that can never start an expression.
+fn prefix_parselet(token: &Token) -> Option<PrefixParselet> {
+    match &token.value {
+        TokenValue::Identifier(_) => Some(parse_identifier),
+        TokenValue::Number(_) => Some(parse_number),
+        TokenValue::String(_) => Some(parse_string),
+        TokenValue::Symbol(Symbol::Asterisk) => Some(parse_star),
+        TokenValue::Symbol(Symbol::LeftParen) => Some(parse_group),
+        _ => None,
+    }
+}
+
+/// Looks up the infix parselet for a token kind, alongside its left binding
+/// power. The grammar has no infix operators yet, but the loop in
+/// [`parse_expr`] is already structured to pick them up here as new
+/// `Symbol`/`Keyword` variants are lexed (e.g. arithmetic or comparison
+/// operators).
+fn infix_parselet(_token: &Token) -> Option<(BindingPower, InfixParselet)> {
+    None
+}
+
+fn parse_expr<'token>(cursor: &mut Cursor<'token>, min_bp: BindingPower) -> ExprResult<'token> {
+    let token = cursor.next().ok_or_else(|| cursor.error())?;
+    let Some(prefix) = prefix_parselet(&token) else {
+        return Err(cursor.error_at(&token));
+    };
+    let mut left = prefix(cursor, token)?;
+
+    while let Some(peeked) = cursor.peek() {
+        let Some((bp, infix)) = infix_parselet(peeked) else {
+            break;
+        };
+        if bp < min_bp {
+            break;
+        }
+        left = infix(cursor, left, bp)?;
+    }
+
+    Ok(left)
+}
+
+fn parse_identifier<'token>(
+    _cursor: &mut Cursor<'token>,
+    token: Token<'token>,
+) -> ExprResult<'token> {
+    match token.value {
+        TokenValue::Identifier(name) => Ok(Expr::Identifier(name.to_string())),
+        _ => unreachable!("prefix_parselet only selects parse_identifier for Identifier tokens"),
+    }
+}
+
+fn parse_number<'token>(_cursor: &mut Cursor<'token>, token: Token<'token>) -> ExprResult<'token> {
+    match token.value {
+        TokenValue::Number(value) => Ok(Expr::Number(value)),
+        _ => unreachable!("prefix_parselet only selects parse_number for Number tokens"),
+    }
+}
+
+fn parse_string<'token>(_cursor: &mut Cursor<'token>, token: Token<'token>) -> ExprResult<'token> {
+    match token.value {
+        TokenValue::String(value) => Ok(Expr::String(value.into_owned())),
+        _ => unreachable!("prefix_parselet only selects parse_string for String tokens"),
+    }
+}
+
+fn parse_star<'token>(_cursor: &mut Cursor<'token>, _token: Token<'token>) -> ExprResult<'token> {
+    Ok(Expr::Star)
+}
+
+/// Parses a parenthesized expression or, if it holds more than one
+/// comma-separated expression, a value tuple.
+fn parse_group<'token>(cursor: &mut Cursor<'token>, _token: Token<'token>) -> ExprResult<'token> {
+    let mut items = parse_expr_list(cursor)?;
+    cursor.expect_symbol(Symbol::RightParen)?;
+    if items.len() == 1 {
+        Ok(items.pop().unwrap())
+    } else {
+        Ok(Expr::Tuple(items))
+    }
+}
+
+fn parse_expr_list<'token>(cursor: &mut Cursor<'token>) -> Result<Vec<Expr>, ParseError<'token>> {
+    let mut items = vec![parse_expr(cursor, MIN_BINDING_POWER)?];
+    while cursor.eat_symbol(Symbol::Comma) {
+        items.push(parse_expr(cursor, MIN_BINDING_POWER)?);
+    }
+    Ok(items)
+}
+
+fn parse_select<'token>(cursor: &mut Cursor<'token>) -> Result<Statement, ParseError<'token>> {
+    cursor.expect_keyword(Keyword::Select)?;
+    let columns = parse_expr_list(cursor)?;
+    cursor.expect_keyword(Keyword::From)?;
+    let table = cursor.expect_identifier()?;
+    Ok(Statement::Select { columns, table })
+}
+
+fn parse_column_def<'token>(cursor: &mut Cursor<'token>) -> Result<ColumnDef, ParseError<'token>> {
+    let name = cursor.expect_identifier()?;
+    let column_type = match cursor.next() {
+        Some(token) => match token.value {
+            TokenValue::Keyword(Keyword::Int) => ColumnType::Int,
+            TokenValue::Keyword(Keyword::Text) => ColumnType::Text,
+            _ => return Err(cursor.error_at(&token)),
+        },
+        None => return Err(cursor.error()),
+    };
+    Ok(ColumnDef { name, column_type })
+}
+
+fn parse_create_table<'token>(
+    cursor: &mut Cursor<'token>,
+) -> Result<Statement, ParseError<'token>> {
+    cursor.expect_keyword(Keyword::Create)?;
+    cursor.expect_keyword(Keyword::Table)?;
+    let name = cursor.expect_identifier()?;
+    cursor.expect_symbol(Symbol::LeftParen)?;
+    let mut columns = vec![parse_column_def(cursor)?];
+    while cursor.eat_symbol(Symbol::Comma) {
+        columns.push(parse_column_def(cursor)?);
+    }
+    cursor.expect_symbol(Symbol::RightParen)?;
+    Ok(Statement::CreateTable { name, columns })
+}
+
+fn parse_insert<'token>(cursor: &mut Cursor<'token>) -> Result<Statement, ParseError<'token>> {
+    cursor.expect_keyword(Keyword::Insert)?;
+    cursor.expect_keyword(Keyword::Into)?;
+    let table = cursor.expect_identifier()?;
+    cursor.expect_keyword(Keyword::Values)?;
+    cursor.expect_symbol(Symbol::LeftParen)?;
+    let values = parse_expr_list(cursor)?;
+    cursor.expect_symbol(Symbol::RightParen)?;
+    Ok(Statement::Insert { table, values })
+}
+
+fn parse_statement<'token>(cursor: &mut Cursor<'token>) -> Result<Statement, ParseError<'token>> {
+    let Some(token) = cursor.peek().cloned() else {
+        return Err(cursor.error());
+    };
+    match token.value {
+        TokenValue::Keyword(Keyword::Select) => parse_select(cursor),
+        TokenValue::Keyword(Keyword::Create) => parse_create_table(cursor),
+        TokenValue::Keyword(Keyword::Insert) => parse_insert(cursor),
+        _ => Err(cursor.error_at(&token)),
+    }
+}
+
+/// Parses a full token stream into the statements it represents: `SELECT
+/// <cols> FROM <table>`, `CREATE TABLE <name> (<col> <type>, ...)`, and
+/// `INSERT INTO <name> VALUES (...)`, each terminated by `;`.
+pub fn parse(tokens: Vec<Token>) -> Result<Vec<Statement>, ParseError> {
+    let mut cursor = Cursor::new(tokens);
+    let mut statements = Vec::new();
+    while cursor.peek().is_some() {
+        statements.push(parse_statement(&mut cursor)?);
+        cursor.expect_symbol(Symbol::Semicolon)?;
+    }
+    Ok(statements)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::lexer::lex;
+
+    #[test]
+    fn select_statement() {
+        let tokens = lex("SELECT a, b FROM t;").unwrap();
+        let statements = parse(tokens).unwrap();
+        assert_eq!(
+            statements,
+            vec![Statement::Select {
+                columns: vec![
+                    Expr::Identifier("a".to_string()),
+                    Expr::Identifier("b".to_string()),
+                ],
+                table: "t".to_string(),
+            }]
+        );
+    }
+
+    #[test]
+    fn select_star() {
+        let tokens = lex("SELECT * FROM t;").unwrap();
+        let statements = parse(tokens).unwrap();
+        assert_eq!(
+            statements,
+            vec![Statement::Select {
+                columns: vec![Expr::Star],
+                table: "t".to_string(),
+            }]
+        );
+    }
+
+    #[test]
+    fn create_table_statement() {
+        let tokens = lex("CREATE TABLE t (a INT, b TEXT);").unwrap();
+        let statements = parse(tokens).unwrap();
+        assert_eq!(
+            statements,
+            vec![Statement::CreateTable {
+                name: "t".to_string(),
+                columns: vec![
+                    ColumnDef {
+                        name: "a".to_string(),
+                        column_type: ColumnType::Int,
+                    },
+                    ColumnDef {
+                        name: "b".to_string(),
+                        column_type: ColumnType::Text,
+                    },
+                ],
+            }]
+        );
+    }
+
+    #[test]
+    fn insert_statement() {
+        let tokens = lex("INSERT INTO t VALUES (1, 'hi');").unwrap();
+        let statements = parse(tokens).unwrap();
+        assert_eq!(
+            statements,
+            vec![Statement::Insert {
+                table: "t".to_string(),
+                values: vec![Expr::Number(1), Expr::String("hi".to_string())],
+            }]
+        );
+    }
+
+    #[test]
+    fn multiple_statements() {
+        let tokens = lex("CREATE TABLE t (a INT); SELECT a FROM t;").unwrap();
+        let statements = parse(tokens).unwrap();
+        assert_eq!(statements.len(), 2);
+    }
+
+    #[test]
+    fn missing_from_is_an_error() {
+        let tokens = lex("SELECT a t;").unwrap();
+        assert!(parse(tokens).is_err());
+    }
+
+    #[test]
+    fn unexpected_statement_start_is_an_error() {
+        let tokens = lex("a FROM t;").unwrap();
+        assert!(parse(tokens).is_err());
+    }
+}